@@ -0,0 +1,237 @@
+use crate::image::{FloatImage, Image};
+
+/// A CIE XYZ reference white point, used to normalize XYZ<->Lab
+/// conversions. Defaults to the D65 illuminant used by sRGB.
+#[derive(Clone, Copy, Debug)]
+pub struct WhitePoint {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl WhitePoint {
+    pub const D65: WhitePoint = WhitePoint {
+        x: 0.95047,
+        y: 1.0,
+        z: 1.08883,
+    };
+}
+
+impl Default for WhitePoint {
+    fn default() -> Self {
+        WhitePoint::D65
+    }
+}
+
+/// Converts an `Image::Rgb` to `Image::Gray` using ITU-R BT.601 luma
+/// weights, matching the coefficients OpenCV and most image libraries use
+/// for `RGB2GRAY`.
+pub fn rgb_to_grayscale(img: &Image) -> Image {
+    let (width, height, data) = match img {
+        Image::Rgb {
+            width,
+            height,
+            data,
+        } => (*width, *height, data),
+        Image::Gray { .. } => return img.clone(),
+    };
+    let mut out = Vec::with_capacity(width * height);
+    for px in data.chunks_exact(3) {
+        let luma = 0.299 * px[0] as f32 + 0.587 * px[1] as f32 + 0.114 * px[2] as f32;
+        out.push(luma.round().clamp(0.0, 255.0) as u8);
+    }
+    Image::gray(width, height, out)
+}
+
+fn srgb_channel_to_linear(v: f32) -> f32 {
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_channel_to_srgb(v: f32) -> f32 {
+    if v <= 0.0031308 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Converts an `Image::Rgb` from gamma-encoded sRGB bytes in `[0, 255]` to
+/// linear-light channels in `[0, 1]`.
+pub fn srgb_to_linear(img: &Image) -> FloatImage {
+    let (width, height, data) = match img {
+        Image::Rgb {
+            width,
+            height,
+            data,
+        } => (*width, *height, data),
+        Image::Gray { .. } => panic!("srgb_to_linear requires an Rgb image"),
+    };
+    let linear: Vec<f32> = data
+        .iter()
+        .map(|&v| srgb_channel_to_linear(v as f32 / 255.0))
+        .collect();
+    FloatImage::new(width, height, 3, linear)
+}
+
+/// Converts a linear-light `FloatImage` back to a gamma-encoded
+/// `Image::Rgb`, clamping to `[0, 255]`.
+pub fn linear_to_srgb(img: &FloatImage) -> Image {
+    assert_eq!(img.channels(), 3, "linear_to_srgb requires 3 channels");
+    let data: Vec<u8> = img
+        .data()
+        .iter()
+        .map(|&v| (linear_channel_to_srgb(v) * 255.0).round().clamp(0.0, 255.0) as u8)
+        .collect();
+    Image::rgb(img.width(), img.height(), data)
+}
+
+// sRGB (D65) <-> CIE XYZ, IEC 61966-2-1.
+const RGB_TO_XYZ: [[f32; 3]; 3] = [
+    [0.4124564, 0.3575761, 0.1804375],
+    [0.2126729, 0.7151522, 0.072175],
+    [0.0193339, 0.119192, 0.9503041],
+];
+const XYZ_TO_RGB: [[f32; 3]; 3] = [
+    [3.2404542, -1.5371385, -0.4985314],
+    [-0.969266, 1.8760108, 0.041556],
+    [0.0556434, -0.2040259, 1.0572252],
+];
+
+fn apply_matrix(img: &FloatImage, m: &[[f32; 3]; 3]) -> FloatImage {
+    assert_eq!(img.channels(), 3, "expected a 3-channel image");
+    let mut out = Vec::with_capacity(img.data().len());
+    for px in img.data().chunks_exact(3) {
+        for row in m {
+            out.push(row[0] * px[0] + row[1] * px[1] + row[2] * px[2]);
+        }
+    }
+    FloatImage::new(img.width(), img.height(), 3, out)
+}
+
+/// Converts a linear-light RGB `FloatImage` to CIE XYZ using the standard
+/// sRGB->XYZ matrix (D65 white point).
+pub fn rgb_to_xyz(img: &FloatImage) -> FloatImage {
+    apply_matrix(img, &RGB_TO_XYZ)
+}
+
+/// Converts a CIE XYZ `FloatImage` back to linear-light RGB.
+pub fn xyz_to_rgb(img: &FloatImage) -> FloatImage {
+    apply_matrix(img, &XYZ_TO_RGB)
+}
+
+fn lab_f(t: f32) -> f32 {
+    const DELTA: f32 = 6.0 / 29.0;
+    if t > DELTA * DELTA * DELTA {
+        t.cbrt()
+    } else {
+        t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+    }
+}
+
+fn lab_f_inv(t: f32) -> f32 {
+    const DELTA: f32 = 6.0 / 29.0;
+    if t > DELTA {
+        t * t * t
+    } else {
+        3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+    }
+}
+
+/// Converts a CIE XYZ `FloatImage` to CIE L*a*b*, normalized against
+/// `white` (e.g. [`WhitePoint::D65`]). `L` is in `[0, 100]`; `a`/`b` are
+/// roughly in `[-128, 127]`.
+pub fn xyz_to_lab(img: &FloatImage, white: WhitePoint) -> FloatImage {
+    assert_eq!(img.channels(), 3, "xyz_to_lab requires 3 channels");
+    let mut out = Vec::with_capacity(img.data().len());
+    for px in img.data().chunks_exact(3) {
+        let fx = lab_f(px[0] / white.x);
+        let fy = lab_f(px[1] / white.y);
+        let fz = lab_f(px[2] / white.z);
+        out.push(116.0 * fy - 16.0);
+        out.push(500.0 * (fx - fy));
+        out.push(200.0 * (fy - fz));
+    }
+    FloatImage::new(img.width(), img.height(), 3, out)
+}
+
+/// Converts a CIE L*a*b* `FloatImage` back to CIE XYZ, normalized against
+/// `white`.
+pub fn lab_to_xyz(img: &FloatImage, white: WhitePoint) -> FloatImage {
+    assert_eq!(img.channels(), 3, "lab_to_xyz requires 3 channels");
+    let mut out = Vec::with_capacity(img.data().len());
+    for px in img.data().chunks_exact(3) {
+        let (l, a, b) = (px[0], px[1], px[2]);
+        let fy = (l + 16.0) / 116.0;
+        let fx = fy + a / 500.0;
+        let fz = fy - b / 200.0;
+        out.push(white.x * lab_f_inv(fx));
+        out.push(white.y * lab_f_inv(fy));
+        out.push(white.z * lab_f_inv(fz));
+    }
+    FloatImage::new(img.width(), img.height(), 3, out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rgb_to_grayscale() {
+        let img = Image::rgb(1, 1, vec![255, 0, 0]);
+        let gray = rgb_to_grayscale(&img);
+        assert_eq!(gray.data(), &vec![76]);
+    }
+
+    #[test]
+    fn test_srgb_linear_round_trip() {
+        let img = Image::rgb(1, 1, vec![10, 128, 250]);
+        let linear = srgb_to_linear(&img);
+        let back = linear_to_srgb(&linear);
+        for (a, b) in img.data().iter().zip(back.data().iter()) {
+            assert!((*a as i32 - *b as i32).abs() <= 1);
+        }
+    }
+
+    #[test]
+    fn test_srgb_to_linear_breakpoint() {
+        assert!((srgb_channel_to_linear(0.0) - 0.0).abs() < 1e-6);
+        assert!((srgb_channel_to_linear(1.0) - 1.0).abs() < 1e-6);
+        // At the 0.04045 breakpoint the linear segment applies, and the
+        // power segment just above it continues from the same value.
+        assert!((srgb_channel_to_linear(0.04045) - 0.04045 / 12.92).abs() < 1e-6);
+        assert!((srgb_channel_to_linear(0.0405) - 0.0405 / 12.92).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_rgb_xyz_round_trip() {
+        let img = FloatImage::new(1, 1, 3, vec![0.3, 0.5, 0.7]);
+        let xyz = rgb_to_xyz(&img);
+        let back = xyz_to_rgb(&xyz);
+        for (a, b) in img.data().iter().zip(back.data().iter()) {
+            assert!((a - b).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_white_point_maps_to_lab_l_100() {
+        let white = FloatImage::new(1, 1, 3, vec![WhitePoint::D65.x, WhitePoint::D65.y, WhitePoint::D65.z]);
+        let lab = xyz_to_lab(&white, WhitePoint::D65);
+        assert!((lab.data()[0] - 100.0).abs() < 1e-3);
+        assert!(lab.data()[1].abs() < 1e-3);
+        assert!(lab.data()[2].abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_xyz_lab_round_trip() {
+        let xyz = FloatImage::new(1, 1, 3, vec![0.2, 0.3, 0.25]);
+        let lab = xyz_to_lab(&xyz, WhitePoint::D65);
+        let back = lab_to_xyz(&lab, WhitePoint::D65);
+        for (a, b) in xyz.data().iter().zip(back.data().iter()) {
+            assert!((a - b).abs() < 1e-4);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use crate::image::Image;
+
+const WEIGHT_LUT_BINS: usize = 2048;
+
+/// Precomputed distance->weight table for the non-local-means kernel
+/// `exp(-max(d - offset, 0) / h^2)`, avoiding a call to `exp` per
+/// candidate patch.
+struct WeightLut {
+    table: Vec<f32>,
+    step: f32,
+}
+
+impl WeightLut {
+    fn new(max_d: f32, h2: f32, offset: f32) -> Self {
+        let step = (max_d / WEIGHT_LUT_BINS as f32).max(1e-6);
+        let table = (0..=WEIGHT_LUT_BINS)
+            .map(|i| {
+                let d = i as f32 * step;
+                let adjusted = (d - offset).max(0.0);
+                (-adjusted / h2).exp()
+            })
+            .collect();
+        WeightLut { table, step }
+    }
+
+    fn weight(&self, d: f32) -> f32 {
+        let idx = (d / self.step).round() as usize;
+        self.table[idx.min(self.table.len() - 1)]
+    }
+}
+
+fn clamp_coord(v: isize, len: usize) -> usize {
+    v.clamp(0, len as isize - 1) as usize
+}
+
+/// Sum of squared differences between the `template_radius`-sized patches
+/// centered on `center_a` and `center_b` in channel `c`, clamping patch
+/// access at the image border.
+fn patch_ssd(
+    data: &[u8],
+    (width, height, channels): (usize, usize, usize),
+    c: usize,
+    center_a: (usize, usize),
+    center_b: (usize, usize),
+    template_radius: usize,
+) -> f32 {
+    let (x0, y0) = center_a;
+    let (x1, y1) = center_b;
+    let r = template_radius as isize;
+    let mut sum = 0.0f32;
+    for dy in -r..=r {
+        for dx in -r..=r {
+            let ax = clamp_coord(x0 as isize + dx, width);
+            let ay = clamp_coord(y0 as isize + dy, height);
+            let bx = clamp_coord(x1 as isize + dx, width);
+            let by = clamp_coord(y1 as isize + dy, height);
+            let a = data[(ay * width + ax) * channels + c] as f32;
+            let b = data[(by * width + bx) * channels + c] as f32;
+            let diff = a - b;
+            sum += diff * diff;
+        }
+    }
+    sum
+}
+
+fn denoise_channels(
+    data: &[u8],
+    width: usize,
+    height: usize,
+    channels: usize,
+    template_radius: usize,
+    search_radius: usize,
+    h: f32,
+) -> Vec<u8> {
+    let patch_side = 2 * template_radius + 1;
+    let patch_area = (patch_side * patch_side) as f32;
+    let h2 = (h * h).max(1e-6);
+    let max_d = patch_area * 255.0 * 255.0;
+    let lut = WeightLut::new(max_d, h2, patch_area);
+
+    let search = search_radius as isize;
+    let mut out = vec![0u8; data.len()];
+    for y in 0..height {
+        for x in 0..width {
+            for c in 0..channels {
+                let mut weight_sum = 0.0f32;
+                let mut value_sum = 0.0f32;
+                for sy in -search..=search {
+                    for sx in -search..=search {
+                        let cx = x as isize + sx;
+                        let cy = y as isize + sy;
+                        if cx < 0 || cy < 0 || cx >= width as isize || cy >= height as isize {
+                            continue;
+                        }
+                        let (cx, cy) = (cx as usize, cy as usize);
+                        let d = patch_ssd(data, (width, height, channels), c, (x, y), (cx, cy), template_radius);
+                        let weight = lut.weight(d);
+                        weight_sum += weight;
+                        value_sum += weight * data[(cy * width + cx) * channels + c] as f32;
+                    }
+                }
+                let v = if weight_sum > 0.0 {
+                    value_sum / weight_sum
+                } else {
+                    data[(y * width + x) * channels + c] as f32
+                };
+                out[(y * width + x) * channels + c] = v.round().clamp(0.0, 255.0) as u8;
+            }
+        }
+    }
+    out
+}
+
+/// Edge-preserving non-local-means denoising. For each pixel, candidate
+/// centers within a `(2*search_radius+1)` square are weighted by the
+/// similarity of their `(2*template_radius+1)`-square patch to the
+/// current pixel's patch (`weight = exp(-max(patch_ssd - patch_area, 0) / h^2)`,
+/// where `patch_area = (2*template_radius+1)^2`), and the
+/// output is the weighted average of candidate center values. Processes
+/// `Image::Rgb` per channel.
+pub fn denoise_nlmeans(img: &Image, template_radius: usize, search_radius: usize, h: f32) -> Image {
+    match img {
+        Image::Gray {
+            width,
+            height,
+            data,
+        } => {
+            let out = denoise_channels(data, *width, *height, 1, template_radius, search_radius, h);
+            Image::gray(*width, *height, out)
+        }
+        Image::Rgb {
+            width,
+            height,
+            data,
+        } => {
+            let out = denoise_channels(data, *width, *height, 3, template_radius, search_radius, h);
+            Image::rgb(*width, *height, out)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_denoise_flat_image_is_unchanged() {
+        let img = Image::gray(10, 10, vec![128u8; 100]);
+        let out = denoise_nlmeans(&img, 1, 2, 10.0);
+        assert_eq!(out.data(), img.data());
+    }
+
+    #[test]
+    fn test_denoise_preserves_dimensions() {
+        let img = Image::rgb(6, 5, vec![50u8; 90]);
+        let out = denoise_nlmeans(&img, 1, 2, 15.0);
+        assert_eq!(out.width(), 6);
+        assert_eq!(out.height(), 5);
+    }
+
+    #[test]
+    fn test_denoise_smooths_salt_and_pepper_noise() {
+        let mut data = vec![100u8; 400];
+        // Scatter a few extreme outliers across an otherwise flat 20x20 image.
+        for idx in [0usize, 37, 99, 150, 213, 301, 350, 399] {
+            data[idx] = if idx % 2 == 0 { 255 } else { 0 };
+        }
+        let img = Image::gray(20, 20, data.clone());
+        let out = denoise_nlmeans(&img, 2, 4, 80.0);
+
+        let variance = |d: &[u8]| -> f64 {
+            let mean = d.iter().map(|&v| v as f64).sum::<f64>() / d.len() as f64;
+            d.iter().map(|&v| (v as f64 - mean).powi(2)).sum::<f64>() / d.len() as f64
+        };
+        assert!(variance(out.data()) < variance(&data));
+    }
+}
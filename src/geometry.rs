@@ -0,0 +1,225 @@
+use crate::filters::{eval_filter, ResizeAlgorithm};
+use crate::image::Image;
+
+/// Row-major 3x3 homography matrix.
+pub type Homography = [[f64; 3]; 3];
+
+/// Solves for the 3x3 homography mapping `src_quad` onto `dst_quad`
+/// (`h33` fixed to 1) by Gaussian elimination on the 8x8 linear system for
+/// the 8 unknown matrix entries. Corners in both quads must be given in
+/// the same winding order (e.g. top-left, top-right, bottom-right,
+/// bottom-left).
+pub fn compute_homography(src_quad: [(f32, f32); 4], dst_quad: [(f32, f32); 4]) -> Homography {
+    let mut a = [[0.0f64; 8]; 8];
+    let mut b = [0.0f64; 8];
+    for i in 0..4 {
+        let (x, y) = (src_quad[i].0 as f64, src_quad[i].1 as f64);
+        let (u, v) = (dst_quad[i].0 as f64, dst_quad[i].1 as f64);
+        a[2 * i] = [x, y, 1.0, 0.0, 0.0, 0.0, -x * u, -y * u];
+        b[2 * i] = u;
+        a[2 * i + 1] = [0.0, 0.0, 0.0, x, y, 1.0, -x * v, -y * v];
+        b[2 * i + 1] = v;
+    }
+    let h = solve_linear_system(a, b);
+    [
+        [h[0], h[1], h[2]],
+        [h[3], h[4], h[5]],
+        [h[6], h[7], 1.0],
+    ]
+}
+
+/// Solves `a * x = b` for an 8x8 system via Gaussian elimination with
+/// partial pivoting.
+fn solve_linear_system(mut a: [[f64; 8]; 8], mut b: [f64; 8]) -> [f64; 8] {
+    for col in 0..8 {
+        let mut pivot = col;
+        for row in (col + 1)..8 {
+            if a[row][col].abs() > a[pivot][col].abs() {
+                pivot = row;
+            }
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let d = a[col][col];
+        for v in a[col][col..].iter_mut() {
+            *v /= d;
+        }
+        b[col] /= d;
+
+        for row in 0..8 {
+            if row == col {
+                continue;
+            }
+            let factor = a[row][col];
+            let pivot_row = a[col];
+            for (v, &p) in a[row][col..].iter_mut().zip(pivot_row[col..].iter()) {
+                *v -= factor * p;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+    b
+}
+
+fn invert_3x3(m: &Homography) -> Homography {
+    let det = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+    let inv_det = 1.0 / det;
+    [
+        [
+            (m[1][1] * m[2][2] - m[1][2] * m[2][1]) * inv_det,
+            (m[0][2] * m[2][1] - m[0][1] * m[2][2]) * inv_det,
+            (m[0][1] * m[1][2] - m[0][2] * m[1][1]) * inv_det,
+        ],
+        [
+            (m[1][2] * m[2][0] - m[1][0] * m[2][2]) * inv_det,
+            (m[0][0] * m[2][2] - m[0][2] * m[2][0]) * inv_det,
+            (m[0][2] * m[1][0] - m[0][0] * m[1][2]) * inv_det,
+        ],
+        [
+            (m[1][0] * m[2][1] - m[1][1] * m[2][0]) * inv_det,
+            (m[0][1] * m[2][0] - m[0][0] * m[2][1]) * inv_det,
+            (m[0][0] * m[1][1] - m[0][1] * m[1][0]) * inv_det,
+        ],
+    ]
+}
+
+fn apply_homography(h: &Homography, x: f32, y: f32) -> (f32, f32) {
+    let (x, y) = (x as f64, y as f64);
+    let w = h[2][0] * x + h[2][1] * y + h[2][2];
+    let px = (h[0][0] * x + h[0][1] * y + h[0][2]) / w;
+    let py = (h[1][0] * x + h[1][1] * y + h[1][2]) / w;
+    (px as f32, py as f32)
+}
+
+fn rect_corners(width: usize, height: usize) -> [(f32, f32); 4] {
+    let (w, h) = ((width as f32) - 1.0, (height as f32) - 1.0);
+    [(0.0, 0.0), (w, 0.0), (w, h), (0.0, h)]
+}
+
+/// Bilinearly samples `data` at fractional source coordinates `(x, y)`,
+/// reusing the same triangle filter the `Bilinear` resize algorithm uses.
+/// Returns `None` (filled with 0 by the caller) when `(x, y)` falls
+/// outside the image.
+fn bilinear_sample(
+    data: &[u8],
+    width: usize,
+    height: usize,
+    channels: usize,
+    x: f32,
+    y: f32,
+) -> Option<Vec<u8>> {
+    if x < 0.0 || y < 0.0 || x > (width - 1) as f32 || y > (height - 1) as f32 {
+        return None;
+    }
+    let x0 = x.floor() as usize;
+    let y0 = y.floor() as usize;
+    let x1 = (x0 + 1).min(width - 1);
+    let y1 = (y0 + 1).min(height - 1);
+
+    // Weights use the true (unclamped) neighbor offset, not the clamped
+    // sample index, so a boundary pixel sampled twice (x1 == x0) still
+    // gets the correct zero weight for its phantom second tap.
+    let wx0 = eval_filter(ResizeAlgorithm::Bilinear, x0 as f32 - x);
+    let wx1 = eval_filter(ResizeAlgorithm::Bilinear, (x0 + 1) as f32 - x);
+    let wy0 = eval_filter(ResizeAlgorithm::Bilinear, y0 as f32 - y);
+    let wy1 = eval_filter(ResizeAlgorithm::Bilinear, (y0 + 1) as f32 - y);
+
+    let mut out = vec![0u8; channels];
+    for (c, v) in out.iter_mut().enumerate() {
+        let sample = data[(y0 * width + x0) * channels + c] as f32 * wx0 * wy0
+            + data[(y0 * width + x1) * channels + c] as f32 * wx1 * wy0
+            + data[(y1 * width + x0) * channels + c] as f32 * wx0 * wy1
+            + data[(y1 * width + x1) * channels + c] as f32 * wx1 * wy1;
+        *v = sample.round().clamp(0.0, 255.0) as u8;
+    }
+    Some(out)
+}
+
+/// Rectifies the quadrilateral `src_quad` (given as image-space corners in
+/// consistent winding order, e.g. a detected document or calibration
+/// target) into a `dst_size` rectangle, by inverse-warping through the
+/// homography from `src_quad` to the destination rectangle and bilinearly
+/// sampling the source. Pixels that map outside the source image are
+/// filled with 0. Supports both `Image::Gray` and `Image::Rgb`.
+pub fn warp_perspective(img: &Image, src_quad: [(f32, f32); 4], dst_size: (usize, usize)) -> Image {
+    let (dst_width, dst_height) = dst_size;
+    let homography = compute_homography(src_quad, rect_corners(dst_width, dst_height));
+    let inverse = invert_3x3(&homography);
+
+    let (width, height, channels, data) = match img {
+        Image::Gray {
+            width,
+            height,
+            data,
+        } => (*width, *height, 1usize, data),
+        Image::Rgb {
+            width,
+            height,
+            data,
+        } => (*width, *height, 3usize, data),
+    };
+
+    let mut out = vec![0u8; dst_width * dst_height * channels];
+    for dy in 0..dst_height {
+        for dx in 0..dst_width {
+            let (sx, sy) = apply_homography(&inverse, dx as f32, dy as f32);
+            if let Some(px) = bilinear_sample(data, width, height, channels, sx, sy) {
+                let o = (dy * dst_width + dx) * channels;
+                out[o..o + channels].copy_from_slice(&px);
+            }
+        }
+    }
+
+    match channels {
+        1 => Image::gray(dst_width, dst_height, out),
+        _ => Image::rgb(dst_width, dst_height, out),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_homography_identity_for_matching_rects() {
+        let quad = [(0.0, 0.0), (3.0, 0.0), (3.0, 3.0), (0.0, 3.0)];
+        let h = compute_homography(quad, quad);
+        for (i, row) in h.iter().enumerate() {
+            for (j, &v) in row.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((v - expected).abs() < 1e-6, "h[{i}][{j}] = {v}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_warp_perspective_identity_rect_is_unchanged() {
+        let data: Vec<u8> = (0..16).map(|v| (v * 16) as u8).collect();
+        let img = Image::gray(4, 4, data.clone());
+        let quad = [(0.0, 0.0), (3.0, 0.0), (3.0, 3.0), (0.0, 3.0)];
+        let warped = warp_perspective(&img, quad, (4, 4));
+        assert_eq!(warped.data(), &data);
+    }
+
+    #[test]
+    fn test_warp_perspective_rgb_output_size() {
+        let img = Image::rgb(4, 4, vec![128u8; 48]);
+        let quad = [(0.5, 0.5), (3.0, 0.0), (3.5, 3.5), (0.0, 3.0)];
+        let warped = warp_perspective(&img, quad, (8, 6));
+        assert_eq!(warped.width(), 8);
+        assert_eq!(warped.height(), 6);
+    }
+
+    #[test]
+    fn test_warp_perspective_out_of_bounds_filled_with_zero() {
+        let img = Image::gray(4, 4, vec![200u8; 16]);
+        // The first source corner sits outside the 4x4 image, so the
+        // destination's top-left corner samples outside the image.
+        let quad = [(-2.0, -2.0), (5.0, -2.0), (5.0, 5.0), (-2.0, 5.0)];
+        let warped = warp_perspective(&img, quad, (8, 8));
+        assert_eq!(warped.data()[0], 0);
+    }
+}
@@ -51,6 +51,44 @@ impl Image {
     }
 }
 
+/// A floating-point image with an arbitrary channel count, used where
+/// colorspace conversions (linear light, XYZ, Lab) need headroom or
+/// negative values that a `u8` buffer can't represent losslessly.
+#[derive(Clone)]
+pub struct FloatImage {
+    width: usize,
+    height: usize,
+    channels: usize,
+    data: Vec<f32>,
+}
+
+impl FloatImage {
+    pub fn new(width: usize, height: usize, channels: usize, data: Vec<f32>) -> Self {
+        assert_eq!(data.len(), width * height * channels);
+        FloatImage {
+            width,
+            height,
+            channels,
+            data,
+        }
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+    pub fn data(&self) -> &Vec<f32> {
+        &self.data
+    }
+    pub fn data_mut(&mut self) -> &mut Vec<f32> {
+        &mut self.data
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -76,4 +114,16 @@ mod tests {
         assert_eq!(img.height(), h);
         assert_eq!(img.data(), &data);
     }
+
+    #[test]
+    fn test_float_image() {
+        let w = 2;
+        let h = 2;
+        let data = vec![0.0f32, 0.5, 1.0, 0.25, 0.75, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7];
+        let img = FloatImage::new(w, h, 3, data.clone());
+        assert_eq!(img.width(), w);
+        assert_eq!(img.height(), h);
+        assert_eq!(img.channels(), 3);
+        assert_eq!(img.data(), &data);
+    }
 }
@@ -0,0 +1,6 @@
+pub mod color;
+pub mod denoise;
+pub mod filters;
+pub mod geometry;
+pub mod image;
+pub mod quality;
@@ -0,0 +1,268 @@
+use std::fmt;
+
+use crate::color::rgb_to_grayscale;
+use crate::filters::{ResizeAlgorithm, Resizer};
+use crate::image::Image;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QualityError {
+    DimensionMismatch { a: (usize, usize), b: (usize, usize) },
+}
+
+impl fmt::Display for QualityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QualityError::DimensionMismatch { a, b } => write!(
+                f,
+                "image dimensions must match: {}x{} vs {}x{}",
+                a.0, a.1, b.0, b.1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for QualityError {}
+
+fn to_luminance(img: &Image) -> Vec<f32> {
+    match img {
+        Image::Gray { data, .. } => data.iter().map(|&v| v as f32).collect(),
+        Image::Rgb { .. } => rgb_to_grayscale(img)
+            .data()
+            .iter()
+            .map(|&v| v as f32)
+            .collect(),
+    }
+}
+
+fn gaussian_kernel(ksize: usize, sigma: f32) -> Vec<f32> {
+    let k = ksize as isize / 2;
+    let mut kernel: Vec<f32> = (-k..=k)
+        .map(|i| (-((i * i) as f32) / (2.0 * sigma * sigma)).exp())
+        .collect();
+    let sum: f32 = kernel.iter().sum();
+    for v in kernel.iter_mut() {
+        *v /= sum;
+    }
+    kernel
+}
+
+fn clamp_idx(i: isize, len: usize) -> usize {
+    i.clamp(0, len as isize - 1) as usize
+}
+
+fn convolve_1d(data: &[f32], width: usize, height: usize, kernel: &[f32], horizontal: bool) -> Vec<f32> {
+    let k = kernel.len() as isize / 2;
+    let mut out = vec![0.0f32; width * height];
+    for y in 0..height {
+        for x in 0..width {
+            let mut acc = 0.0;
+            for (i, &w) in kernel.iter().enumerate() {
+                let (ix, iy) = if horizontal {
+                    (clamp_idx(x as isize + i as isize - k, width), y)
+                } else {
+                    (x, clamp_idx(y as isize + i as isize - k, height))
+                };
+                acc += data[iy * width + ix] * w;
+            }
+            out[y * width + x] = acc;
+        }
+    }
+    out
+}
+
+/// Gaussian-blurs a plain `f32` buffer with edge-replicated borders, used
+/// for the local-window statistics SSIM needs (means, variances,
+/// covariance) where values can go negative or exceed `[0, 255]`.
+fn gaussian_blur_f32(data: &[f32], width: usize, height: usize, ksize: usize, sigma: f32) -> Vec<f32> {
+    let kernel = gaussian_kernel(ksize, sigma);
+    let tmp = convolve_1d(data, width, height, &kernel, true);
+    convolve_1d(&tmp, width, height, &kernel, false)
+}
+
+const SSIM_WINDOW: usize = 11;
+const SSIM_SIGMA: f32 = 1.5;
+const DYNAMIC_RANGE: f32 = 255.0;
+
+/// Image-wide means of the SSIM map (the per-pixel product of the
+/// luminance and contrast-structure similarity terms) and of the
+/// contrast-structure term alone; returned separately because MS-SSIM
+/// uses the latter at every scale but the last.
+fn ssim_components(x: &[f32], y: &[f32], width: usize, height: usize) -> (f32, f32) {
+    let c1 = (0.01 * DYNAMIC_RANGE) * (0.01 * DYNAMIC_RANGE);
+    let c2 = (0.03 * DYNAMIC_RANGE) * (0.03 * DYNAMIC_RANGE);
+
+    let mu_x = gaussian_blur_f32(x, width, height, SSIM_WINDOW, SSIM_SIGMA);
+    let mu_y = gaussian_blur_f32(y, width, height, SSIM_WINDOW, SSIM_SIGMA);
+
+    let xx: Vec<f32> = x.iter().map(|&v| v * v).collect();
+    let yy: Vec<f32> = y.iter().map(|&v| v * v).collect();
+    let xy: Vec<f32> = x.iter().zip(y).map(|(&a, &b)| a * b).collect();
+
+    let ex2 = gaussian_blur_f32(&xx, width, height, SSIM_WINDOW, SSIM_SIGMA);
+    let ey2 = gaussian_blur_f32(&yy, width, height, SSIM_WINDOW, SSIM_SIGMA);
+    let exy = gaussian_blur_f32(&xy, width, height, SSIM_WINDOW, SSIM_SIGMA);
+
+    let n = width * height;
+    let mut ssim_sum = 0.0f64;
+    let mut cs_sum = 0.0f64;
+    for i in 0..n {
+        let mu_x2 = mu_x[i] * mu_x[i];
+        let mu_y2 = mu_y[i] * mu_y[i];
+        let sigma_x2 = ex2[i] - mu_x2;
+        let sigma_y2 = ey2[i] - mu_y2;
+        let sigma_xy = exy[i] - mu_x[i] * mu_y[i];
+
+        let l = (2.0 * mu_x[i] * mu_y[i] + c1) / (mu_x2 + mu_y2 + c1);
+        let cs = (2.0 * sigma_xy + c2) / (sigma_x2 + sigma_y2 + c2);
+        ssim_sum += (l * cs) as f64;
+        cs_sum += cs as f64;
+    }
+    ((ssim_sum / n as f64) as f32, (cs_sum / n as f64) as f32)
+}
+
+fn check_dimensions(a: &Image, b: &Image) -> Result<(), QualityError> {
+    if a.width() != b.width() || a.height() != b.height() {
+        return Err(QualityError::DimensionMismatch {
+            a: (a.width(), a.height()),
+            b: (b.width(), b.height()),
+        });
+    }
+    Ok(())
+}
+
+/// Per-pixel structural-similarity map between `a` and `b`, computed over
+/// an 11x11 Gaussian window (sigma 1.5) following Wang et al. 2004.
+/// Requires `a` and `b` to have equal dimensions.
+pub fn ssim_map(a: &Image, b: &Image) -> Result<Vec<f32>, QualityError> {
+    check_dimensions(a, b)?;
+    let width = a.width();
+    let height = a.height();
+    let x = to_luminance(a);
+    let y = to_luminance(b);
+
+    let c1 = (0.01 * DYNAMIC_RANGE) * (0.01 * DYNAMIC_RANGE);
+    let c2 = (0.03 * DYNAMIC_RANGE) * (0.03 * DYNAMIC_RANGE);
+
+    let mu_x = gaussian_blur_f32(&x, width, height, SSIM_WINDOW, SSIM_SIGMA);
+    let mu_y = gaussian_blur_f32(&y, width, height, SSIM_WINDOW, SSIM_SIGMA);
+    let xx: Vec<f32> = x.iter().map(|&v| v * v).collect();
+    let yy: Vec<f32> = y.iter().map(|&v| v * v).collect();
+    let xy: Vec<f32> = x.iter().zip(&y).map(|(&a, &b)| a * b).collect();
+    let ex2 = gaussian_blur_f32(&xx, width, height, SSIM_WINDOW, SSIM_SIGMA);
+    let ey2 = gaussian_blur_f32(&yy, width, height, SSIM_WINDOW, SSIM_SIGMA);
+    let exy = gaussian_blur_f32(&xy, width, height, SSIM_WINDOW, SSIM_SIGMA);
+
+    let map = (0..width * height)
+        .map(|i| {
+            let mu_x2 = mu_x[i] * mu_x[i];
+            let mu_y2 = mu_y[i] * mu_y[i];
+            let sigma_x2 = ex2[i] - mu_x2;
+            let sigma_y2 = ey2[i] - mu_y2;
+            let sigma_xy = exy[i] - mu_x[i] * mu_y[i];
+            ((2.0 * mu_x[i] * mu_y[i] + c1) * (2.0 * sigma_xy + c2))
+                / ((mu_x2 + mu_y2 + c1) * (sigma_x2 + sigma_y2 + c2))
+        })
+        .collect();
+    Ok(map)
+}
+
+/// Scalar structural-similarity score between `a` and `b`, averaged over
+/// [`ssim_map`]. Requires `a` and `b` to have equal dimensions.
+pub fn ssim(a: &Image, b: &Image) -> Result<f64, QualityError> {
+    let map = ssim_map(a, b)?;
+    Ok(map.iter().map(|&v| v as f64).sum::<f64>() / map.len() as f64)
+}
+
+const MS_SSIM_WEIGHTS: [f32; 5] = [0.0448, 0.2856, 0.3001, 0.2363, 0.1333];
+
+/// Multi-scale SSIM: computes SSIM at [`MS_SSIM_WEIGHTS`].len() progressively
+/// halved resolutions (reusing [`Resizer`] for the downsampling), combining
+/// the per-scale contrast-structure terms with the final scale's full SSIM
+/// using the standard exponent weights. Requires `a` and `b` to have equal
+/// dimensions.
+pub fn ms_ssim(a: &Image, b: &Image) -> Result<f64, QualityError> {
+    check_dimensions(a, b)?;
+
+    let mut cur_a = a.clone();
+    let mut cur_b = b.clone();
+    let mut product = 1.0f64;
+
+    for (i, &weight) in MS_SSIM_WEIGHTS.iter().enumerate() {
+        let width = cur_a.width();
+        let height = cur_a.height();
+        let x = to_luminance(&cur_a);
+        let y = to_luminance(&cur_b);
+        let (full_ssim, cs) = ssim_components(&x, &y, width, height);
+
+        let term = if i == MS_SSIM_WEIGHTS.len() - 1 {
+            full_ssim
+        } else {
+            cs
+        };
+        product *= (term.max(0.0) as f64).powf(weight as f64);
+
+        let is_last = i == MS_SSIM_WEIGHTS.len() - 1;
+        if !is_last {
+            let new_width = (width / 2).max(1);
+            let new_height = (height / 2).max(1);
+            let resizer = Resizer::new(width, height, new_width, new_height, ResizeAlgorithm::Bilinear);
+            cur_a = resizer.resize(&cur_a);
+            cur_b = resizer.resize(&cur_b);
+        }
+    }
+
+    Ok(product)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ssim_identical_images_is_near_one() {
+        let data: Vec<u8> = (0..64).map(|v| (v * 4 % 256) as u8).collect();
+        let img = Image::gray(8, 8, data);
+        let score = ssim(&img, &img).unwrap();
+        assert!((score - 1.0).abs() < 1e-4, "got {score}");
+    }
+
+    #[test]
+    fn test_ssim_different_images_is_lower() {
+        let a = Image::gray(8, 8, vec![0u8; 64]);
+        let mut noisy = vec![0u8; 64];
+        for (i, v) in noisy.iter_mut().enumerate() {
+            *v = ((i * 37) % 256) as u8;
+        }
+        let b = Image::gray(8, 8, noisy);
+        let score = ssim(&a, &b).unwrap();
+        assert!(score < 0.9, "got {score}");
+    }
+
+    #[test]
+    fn test_ssim_dimension_mismatch_errors() {
+        let a = Image::gray(4, 4, vec![0u8; 16]);
+        let b = Image::gray(2, 2, vec![0u8; 4]);
+        assert_eq!(
+            ssim(&a, &b),
+            Err(QualityError::DimensionMismatch {
+                a: (4, 4),
+                b: (2, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_ms_ssim_identical_images_is_near_one() {
+        let data: Vec<u8> = (0..64 * 64).map(|v| (v * 3 % 256) as u8).collect();
+        let img = Image::gray(64, 64, data);
+        let score = ms_ssim(&img, &img).unwrap();
+        assert!((score - 1.0).abs() < 1e-3, "got {score}");
+    }
+
+    #[test]
+    fn test_ms_ssim_dimension_mismatch_errors() {
+        let a = Image::gray(4, 4, vec![0u8; 16]);
+        let b = Image::gray(2, 2, vec![0u8; 4]);
+        assert!(ms_ssim(&a, &b).is_err());
+    }
+}